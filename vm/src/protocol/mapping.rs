@@ -0,0 +1,171 @@
+use std::fmt::Debug;
+
+use crate::{
+    common::lock::OnceCell, PyObject, PyObjectRef, PyResult, TypeProtocol, VirtualMachine,
+};
+
+// Mapping Protocol
+// https://docs.python.org/3/c-api/mapping.html
+
+#[allow(clippy::type_complexity)]
+#[derive(Default, Clone, Copy)]
+pub struct PyMappingMethods {
+    pub length: Option<fn(PyObjectRef, &VirtualMachine) -> PyResult<usize>>,
+    pub subscript: Option<fn(PyObjectRef, PyObjectRef, &VirtualMachine) -> PyResult>,
+    pub ass_subscript:
+        Option<fn(PyObjectRef, PyObjectRef, Option<PyObjectRef>, &VirtualMachine) -> PyResult<()>>,
+}
+
+impl PyMappingMethods {
+    pub const fn not_implemented() -> Self {
+        Self {
+            length: None,
+            subscript: None,
+            ass_subscript: None,
+        }
+    }
+}
+
+impl Debug for PyMappingMethods {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PyMappingMethods")
+            .field("length", &self.length.map(|x| x as usize))
+            .field("subscript", &self.subscript.map(|x| x as usize))
+            .field("ass_subscript", &self.ass_subscript.map(|x| x as usize))
+            .finish()
+    }
+}
+
+pub struct PyMapping<'a> {
+    pub obj: &'a PyObject,
+    // some function don't need it, so lazy initialize
+    methods: OnceCell<PyMappingMethods>,
+}
+
+impl<'a> From<&'a PyObject> for PyMapping<'a> {
+    fn from(obj: &'a PyObject) -> Self {
+        Self {
+            obj,
+            methods: OnceCell::new(),
+        }
+    }
+}
+
+impl<'a> PyMapping<'a> {
+    pub fn with_methods(obj: &'a PyObject, methods: PyMappingMethods) -> Self {
+        Self {
+            obj,
+            methods: OnceCell::from(methods),
+        }
+    }
+}
+
+impl PyMapping<'_> {
+    // PyMapping_Check
+    pub fn has_protocol(&self, vm: &VirtualMachine) -> bool {
+        self.methods(vm).subscript.is_some()
+    }
+
+    pub fn try_protocol(&self, vm: &VirtualMachine) -> PyResult<()> {
+        if self.has_protocol(vm) {
+            Ok(())
+        } else {
+            Err(vm.new_type_error(format!("'{}' is not a mapping", self.obj.class().name())))
+        }
+    }
+
+    pub fn methods(&self, vm: &VirtualMachine) -> &PyMappingMethods {
+        self.methods.get_or_init(|| {
+            self.obj
+                .class()
+                .mro_find_map(|x| x.slots.as_mapping.load())
+                .map(|f| f(self.obj, vm))
+                .unwrap_or_else(PyMappingMethods::not_implemented)
+        })
+    }
+
+    pub fn length(&self, vm: &VirtualMachine) -> PyResult<usize> {
+        if let Some(f) = self.methods(vm).length {
+            f(self.obj.to_owned(), vm)
+        } else {
+            Err(vm.new_type_error(format!(
+                "'{}' is not a mapping or has no len()",
+                self.obj.class().name()
+            )))
+        }
+    }
+
+    pub fn subscript(&self, needle: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+        if let Some(f) = self.methods(vm).subscript {
+            f(self.obj.to_owned(), needle, vm)
+        } else {
+            Err(vm.new_type_error(format!(
+                "'{}' object is not subscriptable",
+                self.obj.class().name()
+            )))
+        }
+    }
+
+    pub(crate) fn _ass_subscript(
+        &self,
+        needle: PyObjectRef,
+        value: Option<PyObjectRef>,
+        vm: &VirtualMachine,
+    ) -> PyResult<()> {
+        if let Some(f) = self.methods(vm).ass_subscript {
+            f(self.obj.to_owned(), needle, value, vm)
+        } else {
+            Err(vm.new_type_error(format!(
+                "'{}' object does not support item {}",
+                self.obj.class().name(),
+                if value.is_some() {
+                    "assignment"
+                } else {
+                    "deletion"
+                }
+            )))
+        }
+    }
+
+    pub fn ass_subscript(
+        &self,
+        needle: PyObjectRef,
+        value: PyObjectRef,
+        vm: &VirtualMachine,
+    ) -> PyResult<()> {
+        self._ass_subscript(needle, Some(value), vm)
+    }
+
+    pub fn del_subscript(&self, needle: PyObjectRef, vm: &VirtualMachine) -> PyResult<()> {
+        self._ass_subscript(needle, None, vm)
+    }
+
+    pub fn get_item(&self, needle: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+        self.subscript(needle, vm)
+    }
+
+    pub fn set_item(
+        &self,
+        needle: PyObjectRef,
+        value: PyObjectRef,
+        vm: &VirtualMachine,
+    ) -> PyResult<()> {
+        self.ass_subscript(needle, value, vm)
+    }
+
+    pub fn del_item(&self, needle: PyObjectRef, vm: &VirtualMachine) -> PyResult<()> {
+        self.del_subscript(needle, vm)
+    }
+
+    pub fn keys(&self, vm: &VirtualMachine) -> PyResult {
+        vm.call_method(self.obj, "keys", ())
+    }
+
+    pub fn values(&self, vm: &VirtualMachine) -> PyResult {
+        vm.call_method(self.obj, "values", ())
+    }
+
+    pub fn items(&self, vm: &VirtualMachine) -> PyResult {
+        vm.call_method(self.obj, "items", ())
+    }
+}
@@ -0,0 +1,5 @@
+mod mapping;
+mod sequence;
+
+pub use mapping::{PyMapping, PyMappingMethods};
+pub use sequence::{PySequence, PySequenceMethods};
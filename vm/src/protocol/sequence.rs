@@ -3,8 +3,9 @@ use std::fmt::Debug;
 
 use itertools::Itertools;
 
+use super::PyMapping;
 use crate::{
-    builtins::{PyList, PySlice},
+    builtins::{PyList, PySlice, PyTuple},
     common::lock::OnceCell,
     function::IntoPyObject,
     IdProtocol, PyArithmeticValue, PyObject, PyObjectPayload, PyObjectRef, PyObjectView, PyResult,
@@ -19,13 +20,13 @@ use crate::{
 pub struct PySequenceMethods {
     pub length: Option<fn(&PySequence, &VirtualMachine) -> PyResult<usize>>,
     pub concat: Option<fn(&PySequence, &PyObject, &VirtualMachine) -> PyResult>,
-    pub repeat: Option<fn(&PySequence, usize, &VirtualMachine) -> PyResult>,
+    pub repeat: Option<fn(&PySequence, isize, &VirtualMachine) -> PyResult>,
     pub item: Option<fn(&PySequence, isize, &VirtualMachine) -> PyResult>,
     pub ass_item:
         Option<fn(&PySequence, isize, Option<PyObjectRef>, &VirtualMachine) -> PyResult<()>>,
     pub contains: Option<fn(&PySequence, &PyObject, &VirtualMachine) -> PyResult<bool>>,
     pub inplace_concat: Option<fn(&PySequence, &PyObject, &VirtualMachine) -> PyResult>,
-    pub inplace_repeat: Option<fn(&PySequence, usize, &VirtualMachine) -> PyResult>,
+    pub inplace_repeat: Option<fn(&PySequence, isize, &VirtualMachine) -> PyResult>,
 }
 
 impl PySequenceMethods {
@@ -136,7 +137,27 @@ impl PySequence<'_> {
         )))
     }
 
-    pub fn repeat(&self, n: usize, vm: &VirtualMachine) -> PyResult {
+    // CPython's `PySequence_Repeat`: n <= 0 yields an empty sequence of the
+    // same type, and an overly large multiplier raises `OverflowError`
+    // rather than silently wrapping.
+    fn repeat_overflow_check(&self, n: isize, vm: &VirtualMachine) -> PyResult<()> {
+        if n > 0 {
+            if let Some(length) = self.methods(vm).length {
+                let len = length(self, vm)?;
+                if (len as isize).checked_mul(n).is_none() {
+                    return Err(vm.new_overflow_error(
+                        "cannot fit 'int' into an index-sized integer".to_string(),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn repeat(&self, n: isize, vm: &VirtualMachine) -> PyResult {
+        let n = n.max(0);
+        self.repeat_overflow_check(n, vm)?;
+
         if let Some(f) = self.methods(vm).repeat {
             return f(self, n, vm);
         }
@@ -175,7 +196,10 @@ impl PySequence<'_> {
         )))
     }
 
-    pub fn inplace_repeat(&self, n: usize, vm: &VirtualMachine) -> PyResult {
+    pub fn inplace_repeat(&self, n: isize, vm: &VirtualMachine) -> PyResult {
+        let n = n.max(0);
+        self.repeat_overflow_check(n, vm)?;
+
         if let Some(f) = self.methods(vm).inplace_repeat {
             return f(self, n, vm);
         }
@@ -197,6 +221,13 @@ impl PySequence<'_> {
 
     pub fn get_item(&self, i: isize, vm: &VirtualMachine) -> PyResult {
         if let Some(f) = self.methods(vm).item {
+            let mut i = i;
+            if i < 0 {
+                if let Some(length) = self.methods(vm).length {
+                    let len = length(self, vm)?;
+                    i += len as isize;
+                }
+            }
             return f(self, i, vm);
         }
         Err(vm.new_type_error(format!(
@@ -207,6 +238,13 @@ impl PySequence<'_> {
 
     fn _ass_item(&self, i: isize, value: Option<PyObjectRef>, vm: &VirtualMachine) -> PyResult<()> {
         if let Some(f) = self.methods(vm).ass_item {
+            let mut i = i;
+            if i < 0 {
+                if let Some(length) = self.methods(vm).length {
+                    let len = length(self, vm)?;
+                    i += len as isize;
+                }
+            }
             return f(self, i, value, vm);
         }
         Err(vm.new_type_error(format!(
@@ -229,17 +267,14 @@ impl PySequence<'_> {
     }
 
     pub fn get_slice(&self, start: isize, stop: isize, vm: &VirtualMachine) -> PyResult {
-        if let Some(f) = self.obj.class().mro_find_map(|x| x.slots.as_mapping.load()) {
-            let mp = f(self.obj, vm);
-            if let Some(subscript) = mp.subscript {
-                let slice = PySlice {
-                    start: Some(start.into_pyobject(vm)),
-                    stop: stop.into_pyobject(vm),
-                    step: None,
-                };
-
-                return subscript(self.obj.to_owned(), slice.into_object(vm), vm);
-            }
+        let mapping = PyMapping::from(self.obj);
+        if mapping.has_protocol(vm) {
+            let slice = PySlice {
+                start: Some(start.into_pyobject(vm)),
+                stop: stop.into_pyobject(vm),
+                step: None,
+            };
+            return mapping.subscript(slice.into_object(vm), vm);
         }
         Err(vm.new_type_error(format!(
             "'{}' object is unsliceable",
@@ -254,19 +289,14 @@ impl PySequence<'_> {
         value: Option<PyObjectRef>,
         vm: &VirtualMachine,
     ) -> PyResult<()> {
-        let cls = self.obj.class();
-        if let Some(f) = cls.mro_find_map(|x| x.slots.as_mapping.load()) {
-            drop(cls);
-            let mp = f(self.obj, vm);
-            if let Some(ass_subscript) = mp.ass_subscript {
-                let slice = PySlice {
-                    start: Some(start.into_pyobject(vm)),
-                    stop: stop.into_pyobject(vm),
-                    step: None,
-                };
-
-                return ass_subscript(self.obj.to_owned(), slice.into_object(vm), value, vm);
-            }
+        let mapping = PyMapping::from(self.obj);
+        if mapping.methods(vm).ass_subscript.is_some() {
+            let slice = PySlice {
+                start: Some(start.into_pyobject(vm)),
+                stop: stop.into_pyobject(vm),
+                step: None,
+            };
+            return mapping._ass_subscript(slice.into_object(vm), value, vm);
         }
         Err(vm.new_type_error(format!(
             "'{}' object doesn't support slice {}",
@@ -313,14 +343,53 @@ impl PySequence<'_> {
         Ok(list.into())
     }
 
+    /// The `list`/`tuple` fast path shared by `extract_fast`, `contains`, and
+    /// `index`: a borrowed/cloned view of the backing buffer, skipping the
+    /// iterator protocol. Any lock taken to read a `list`'s backing buffer is
+    /// released as soon as the elements are snapshotted, so a user's
+    /// `__eq__` can't deadlock on it or observe a half-mutated buffer.
+    fn try_fast_items(&self) -> Option<Cow<'_, [PyObjectRef]>> {
+        if let Some(list) = self.obj.payload::<PyList>() {
+            return Some(Cow::Owned(list.borrow_vec().to_vec()));
+        }
+        if let Some(tuple) = self.obj.payload::<PyTuple>() {
+            return Some(Cow::Borrowed(tuple.as_slice()));
+        }
+        None
+    }
+
+    /// Extract the sequence's elements without going through the iterator
+    /// protocol when it's already a concrete `list` or `tuple`, mirroring
+    /// CPython's `PySequence_Fast`. Other iterables are fully materialized,
+    /// so this should only be used where the whole sequence is needed
+    /// anyway (e.g. `count`) — `contains`/`index` must keep iterating lazily
+    /// so they can short-circuit on the first match.
+    pub fn extract_fast(&self, vm: &VirtualMachine) -> PyResult<Cow<'_, [PyObjectRef]>> {
+        if let Some(items) = self.try_fast_items() {
+            return Ok(items);
+        }
+
+        let iter = self.obj.to_owned().get_iter(vm)?;
+        let iter = iter.iter::<PyObjectRef>(vm)?;
+        Ok(Cow::Owned(iter.collect::<PyResult<Vec<_>>>()?))
+    }
+
     pub fn contains(&self, target: &PyObject, vm: &VirtualMachine) -> PyResult<bool> {
         if let Some(f) = self.methods(vm).contains {
             return f(self, target, vm);
         }
 
+        if let Some(items) = self.try_fast_items() {
+            for elem in items.iter() {
+                if vm.bool_eq(elem, target)? {
+                    return Ok(true);
+                }
+            }
+            return Ok(false);
+        }
+
         let iter = self.obj.to_owned().get_iter(vm)?;
         let iter = iter.iter::<PyObjectRef>(vm)?;
-
         for elem in iter {
             let elem = elem?;
             if vm.bool_eq(&elem, target)? {
@@ -333,12 +402,8 @@ impl PySequence<'_> {
     pub fn count(&self, target: &PyObject, vm: &VirtualMachine) -> PyResult<usize> {
         let mut n = 0;
 
-        let iter = self.obj.to_owned().get_iter(vm)?;
-        let iter = iter.iter::<PyObjectRef>(vm)?;
-
-        for elem in iter {
-            let elem = elem?;
-            if vm.bool_eq(&elem, target)? {
+        for elem in self.extract_fast(vm)?.iter() {
+            if vm.bool_eq(elem, target)? {
                 if n == isize::MAX as usize {
                     return Err(vm.new_overflow_error("index exceeds C integer size".to_string()));
                 }
@@ -350,21 +415,30 @@ impl PySequence<'_> {
     }
 
     pub fn index(&self, target: &PyObject, vm: &VirtualMachine) -> PyResult<usize> {
-        let mut index: isize = -1;
+        if let Some(items) = self.try_fast_items() {
+            for (index, elem) in items.iter().enumerate() {
+                if index == isize::MAX as usize {
+                    return Err(vm.new_overflow_error("index exceeds C integer size".to_string()));
+                }
+                if vm.bool_eq(elem, target)? {
+                    return Ok(index);
+                }
+            }
+            return Err(vm.new_value_error("sequence.index(x): x not in sequence".to_string()));
+        }
 
         let iter = self.obj.to_owned().get_iter(vm)?;
         let iter = iter.iter::<PyObjectRef>(vm)?;
-
+        let mut index: usize = 0;
         for elem in iter {
-            if index == isize::MAX {
+            if index == isize::MAX as usize {
                 return Err(vm.new_overflow_error("index exceeds C integer size".to_string()));
             }
-            index += 1;
-
             let elem = elem?;
             if vm.bool_eq(&elem, target)? {
-                return Ok(index as usize);
+                return Ok(index);
             }
+            index += 1;
         }
 
         Err(vm.new_value_error("sequence.index(x): x not in sequence".to_string()))